@@ -0,0 +1,308 @@
+use std::{collections::HashMap, fmt, fs, io, path::Path, rc::Rc};
+
+use serde::Deserialize;
+
+use crate::{EdgeParseError, Forward, Hazard, Pipeline, Stage};
+
+#[derive(Debug, Deserialize)]
+struct RawPipeline {
+    name: String,
+    description: String,
+    clock: String,
+    stages: Vec<RawStage>,
+    #[serde(default)]
+    hazards: Vec<RawHazard>,
+    #[serde(default)]
+    forwards: Vec<RawForward>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawStage {
+    name: String,
+    description: String,
+    stall: Option<String>,
+    flush: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawHazard {
+    name: String,
+    description: String,
+    condition: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawForward {
+    name: String,
+    description: String,
+    condition: String,
+    from: String,
+    to: String,
+}
+
+/// Errors that can happen while loading a [`Pipeline`] definition from disk.
+#[derive(Debug)]
+pub enum LoaderError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Edge(EdgeParseError),
+    UnknownStage(String),
+}
+
+impl fmt::Display for LoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoaderError::Io(err) => write!(f, "could not read pipeline definition: {err}"),
+            LoaderError::Parse(err) => write!(f, "could not parse pipeline definition: {err}"),
+            LoaderError::Edge(err) => write!(f, "could not parse clock edge: {err}"),
+            LoaderError::UnknownStage(name) => {
+                write!(f, "forward refers to unknown stage {name:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoaderError {}
+
+impl From<io::Error> for LoaderError {
+    fn from(err: io::Error) -> Self {
+        LoaderError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for LoaderError {
+    fn from(err: toml::de::Error) -> Self {
+        LoaderError::Parse(err)
+    }
+}
+
+impl From<EdgeParseError> for LoaderError {
+    fn from(err: EdgeParseError) -> Self {
+        LoaderError::Edge(err)
+    }
+}
+
+/// Loads a [`Pipeline`] from a TOML definition file, resolving `Forward::from`/`to`
+/// to the very same `Rc<Stage>` instances held in `Pipeline::stages`.
+pub fn load_pipeline(path: impl AsRef<Path>) -> Result<Pipeline, LoaderError> {
+    let src = fs::read_to_string(path)?;
+    let raw: RawPipeline = toml::from_str(&src)?;
+
+    let mut by_name: HashMap<String, Rc<Stage>> = HashMap::new();
+
+    let stages = raw
+        .stages
+        .into_iter()
+        .map(|stage| {
+            let stage = Rc::new(Stage {
+                name: stage.name,
+                description: stage.description,
+                stall: stage.stall,
+                flush: stage.flush,
+            });
+
+            by_name.insert(stage.name.clone(), stage.clone());
+            stage
+        })
+        .collect();
+
+    let hazards = raw
+        .hazards
+        .into_iter()
+        .map(|hazard| Hazard {
+            name: hazard.name,
+            description: hazard.description,
+            condition: hazard.condition,
+        })
+        .collect();
+
+    let forwards = raw
+        .forwards
+        .into_iter()
+        .map(|forward| {
+            let from = by_name
+                .get(&forward.from)
+                .cloned()
+                .ok_or_else(|| LoaderError::UnknownStage(forward.from.clone()))?;
+
+            let to = by_name
+                .get(&forward.to)
+                .cloned()
+                .ok_or_else(|| LoaderError::UnknownStage(forward.to.clone()))?;
+
+            Ok(Forward {
+                name: forward.name,
+                description: forward.description,
+                condition: forward.condition,
+                from,
+                to,
+            })
+        })
+        .collect::<Result<Vec<_>, LoaderError>>()?;
+
+    Ok(Pipeline {
+        name: raw.name,
+        description: raw.description,
+        clock: raw.clock.parse()?,
+        stages,
+        hazards,
+        forwards,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        rc::Rc,
+        sync::atomic::{AtomicU32, Ordering},
+    };
+
+    use super::*;
+    use crate::Edge;
+
+    #[test]
+    fn parses_posedge() {
+        assert!(matches!("posedge clock".parse(), Ok(Edge::Posedge(signal)) if signal == "clock"));
+    }
+
+    #[test]
+    fn parses_negedge() {
+        assert!(matches!("negedge clk".parse(), Ok(Edge::Negedge(signal)) if signal == "clk"));
+    }
+
+    #[test]
+    fn parses_generic_edge() {
+        assert!(matches!("edge x".parse(), Ok(Edge::Edge(signal)) if signal == "x"));
+    }
+
+    #[test]
+    fn rejects_unknown_keyword() {
+        let err: Result<Edge, _> = "tripledge clock".parse();
+        assert!(err.is_err());
+    }
+
+    /// Writes `src` to a throwaway file under the system temp dir and returns its path,
+    /// so `load_pipeline` can be exercised without a checked-in fixture file.
+    fn write_fixture(name: &str, src: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let path =
+            std::env::temp_dir().join(format!("gac-trabajo-loader-test-{name}-{unique}.toml"));
+        fs::write(&path, src).unwrap();
+        path
+    }
+
+    #[test]
+    fn shares_rc_stage_instances_between_stages_and_forwards() {
+        let path = write_fixture(
+            "shares-rc",
+            r#"
+                name = "demo"
+                description = "a tiny pipeline"
+                clock = "posedge clock"
+
+                [[stages]]
+                name = "EX"
+                description = "execute"
+
+                [[stages]]
+                name = "ID"
+                description = "decode"
+
+                [[forwards]]
+                name = "ex_to_id"
+                description = "forward ALU result"
+                condition = "valid"
+                from = "EX"
+                to = "ID"
+            "#,
+        );
+
+        let pipe = load_pipeline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let ex = &pipe.stages[0];
+        let id = &pipe.stages[1];
+        let forward = &pipe.forwards[0];
+
+        assert!(Rc::ptr_eq(&forward.from, ex));
+        assert!(Rc::ptr_eq(&forward.to, id));
+    }
+
+    #[test]
+    fn round_trips_hazards() {
+        let path = write_fixture(
+            "hazard-roundtrip",
+            r#"
+                name = "demo"
+                description = "a tiny pipeline"
+                clock = "posedge clock"
+
+                [[stages]]
+                name = "ID"
+                description = "decode"
+
+                [[hazards]]
+                name = "load-use"
+                description = "load followed by dependent use"
+                condition = "load_use_detected"
+            "#,
+        );
+
+        let pipe = load_pipeline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(pipe.hazards.len(), 1);
+        assert_eq!(pipe.hazards[0].name, "load-use");
+        assert_eq!(
+            pipe.hazards[0].description,
+            "load followed by dependent use"
+        );
+        assert_eq!(pipe.hazards[0].condition, "load_use_detected");
+    }
+
+    #[test]
+    fn rejects_forward_to_unknown_stage() {
+        let path = write_fixture(
+            "unknown-stage",
+            r#"
+                name = "demo"
+                description = "a tiny pipeline"
+                clock = "posedge clock"
+
+                [[stages]]
+                name = "ID"
+                description = "decode"
+
+                [[forwards]]
+                name = "stray"
+                description = "points nowhere"
+                condition = "valid"
+                from = "ID"
+                to = "MEM"
+            "#,
+        );
+
+        let err = load_pipeline(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, LoaderError::UnknownStage(stage) if stage == "MEM"));
+    }
+
+    #[test]
+    fn rejects_malformed_toml() {
+        let path = write_fixture("malformed", "this is not [valid toml");
+
+        let err = load_pipeline(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, LoaderError::Parse(_)));
+    }
+
+    #[test]
+    fn reports_io_error_for_missing_file() {
+        let err = load_pipeline("/no/such/path/gac-trabajo-fixture.toml").unwrap_err();
+        assert!(matches!(err, LoaderError::Io(_)));
+    }
+}
@@ -1,4 +1,15 @@
-use std::{borrow::Borrow, rc::Rc};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    rc::Rc,
+    str::FromStr,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+mod loader;
+mod validate;
 
 #[macro_export]
 macro_rules! helper {
@@ -66,12 +77,41 @@ struct Pipeline {
 }
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)] // `Edge` mirrors the generic "edge x" keyword, see `FromStr` below
 enum Edge {
     Posedge(String),
     Negedge(String),
     Edge(String),
 }
 
+#[derive(Debug)]
+struct EdgeParseError(String);
+
+impl fmt::Display for EdgeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown edge keyword: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for EdgeParseError {}
+
+impl FromStr for Edge {
+    type Err = EdgeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let keyword = words.next().unwrap_or_default();
+        let signal = words.next().unwrap_or_default().to_string();
+
+        match keyword {
+            "posedge" => Ok(Edge::Posedge(signal)),
+            "negedge" => Ok(Edge::Negedge(signal)),
+            "edge" => Ok(Edge::Edge(signal)),
+            _ => Err(EdgeParseError(s.into())),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Stage {
     name: String,
@@ -96,204 +136,656 @@ struct Forward {
     to: Rc<Stage>,
 }
 
-fn generate_stage_report(stage: &Rc<Stage>) -> String {
-    let Stage {
-        name, stall, flush, ..
-    } = stage.borrow();
+/// An output target for a [`Pipeline`]: something that knows how to render a
+/// preamble, a section header, and a one-line report for each kind of element.
+///
+/// `generate_testbench` renders through this trait (taken as a `&dyn Backend`, so the
+/// CLI can pick one at runtime) so the datapath model stays decoupled from any
+/// particular HDL's `$display`/ANSI formatting.
+trait Backend {
+    fn preamble(&self, pipe: &Pipeline) -> String;
+    fn section_header(&self, title: &str) -> String;
+    /// Separates one section's reports from the next (e.g. a blank `$display();`).
+    fn separator(&self) -> String;
+    fn stage_report(&self, stage: &Stage) -> String;
+    fn hazard_report(&self, hazard: &Hazard) -> String;
+    fn forward_report(&self, forward: &Forward) -> String;
+    fn footer(&self, pipe: &Pipeline) -> String;
+}
 
-    let _stall_ = stall.clone().unwrap_or("1'b0".into());
-    let _flush_ = flush.clone().unwrap_or("1'b0".into());
+/// The default backend: a SystemVerilog testbench with colorized `$display` output.
+#[derive(Debug, Default)]
+struct SystemVerilogBackend;
 
-    format!(r#"$display("%s {name}\x1B[0m", status({_stall_}, {_flush_}));"#)
-}
+impl Backend for SystemVerilogBackend {
+    fn preamble(&self, pipe: &Pipeline) -> String {
+        let Pipeline {
+            clock,
+            name,
+            description,
+            ..
+        } = pipe;
+
+        let _clock_ = match clock {
+            Edge::Posedge(name) => format!("posedge {name}"),
+            Edge::Negedge(name) => format!("negedge {name}"),
+            Edge::Edge(name) => format!("edge {name}"),
+        };
+
+        format!(
+            r#"function string status(input stall, flush);
+
+    case (1'b1)
+        flush:   return "\x1B[1;31m【FLUSH】 \x1B[0m";
+        stall:   return "\x1B[1;33m【STALL】 \x1B[0m";
+        default: return "\x1B[1;32m【ACTIVE】\x1B[0m";
+    endcase
+
+endfunction
+
+function string hazard_mark(input condition);
+
+    if (condition) return "\x1B[1;33m ⚠ ";
+    else           return "\x1B[1;32m ∅ ";
+
+endfunction
+
+function string forward_mark(input condition);
+
+    if (condition) return "\x1B[1;33m → ";
+    else           return "\x1B[1;37m ∅ ";
+
+endfunction
+
+initial begin
+
+    $display("███   {name}: {description}   ███");
+    $display();
+    $display();
+
+end
+
+always @({_clock_}) begin
+
+	$display("───────────────────────────────────────────────────");
+	$display();
+"#
+        )
+    }
+
+    fn section_header(&self, title: &str) -> String {
+        format!(r#"$display("= {title} =");"#)
+    }
+
+    fn separator(&self) -> String {
+        "$display();".into()
+    }
+
+    fn stage_report(&self, stage: &Stage) -> String {
+        let Stage {
+            name, stall, flush, ..
+        } = stage;
+
+        let _stall_ = stall.clone().unwrap_or("1'b0".into());
+        let _flush_ = flush.clone().unwrap_or("1'b0".into());
+
+        format!(r#"$display("%s {name}\x1B[0m", status({_stall_}, {_flush_}));"#)
+    }
 
-fn generate_hazard_report(stage: &Hazard) -> String {
-    let Hazard {
-        name, condition, ..
-    } = stage;
+    fn hazard_report(&self, hazard: &Hazard) -> String {
+        let Hazard {
+            name, condition, ..
+        } = hazard;
 
-    format!(r#"$display("%s {name}\x1B[0m", hazard_mark({condition}));"#)
+        format!(r#"$display("%s {name}\x1B[0m", hazard_mark({condition}));"#)
+    }
+
+    fn forward_report(&self, forward: &Forward) -> String {
+        let Forward {
+            name, condition, ..
+        } = forward;
+
+        format!(r#"$display("%s {name}\x1B[0m", forward_mark({condition}));"#)
+    }
+
+    fn footer(&self, _pipe: &Pipeline) -> String {
+        "    $display();\n\nend".into()
+    }
 }
 
-fn generate_forward_report(stage: &Forward) -> String {
-    let Forward {
-        name, condition, ..
-    } = stage;
+/// An alternative backend that reports on a pipeline as plain, uncolored text
+/// instead of a SystemVerilog testbench — handy for logging or piping to other tools.
+#[derive(Debug, Default)]
+struct PlainTextBackend;
+
+impl Backend for PlainTextBackend {
+    fn preamble(&self, pipe: &Pipeline) -> String {
+        format!("{}: {}\n", pipe.name, pipe.description)
+    }
+
+    fn section_header(&self, title: &str) -> String {
+        format!("-- {title} --")
+    }
+
+    fn separator(&self) -> String {
+        String::new()
+    }
+
+    fn stage_report(&self, stage: &Stage) -> String {
+        let Stage {
+            name, stall, flush, ..
+        } = stage;
+
+        let _stall_ = stall.clone().unwrap_or("idle".into());
+        let _flush_ = flush.clone().unwrap_or("idle".into());
+
+        format!("{name}: stall={_stall_} flush={_flush_}")
+    }
 
-    format!(r#"$display("%s {name}\x1B[0m", forward_mark({condition}));"#)
+    fn hazard_report(&self, hazard: &Hazard) -> String {
+        let Hazard {
+            name, condition, ..
+        } = hazard;
+
+        format!("{name}: {condition}")
+    }
+
+    fn forward_report(&self, forward: &Forward) -> String {
+        let Forward {
+            name, condition, ..
+        } = forward;
+
+        format!("{name}: {condition}")
+    }
+
+    fn footer(&self, _pipe: &Pipeline) -> String {
+        String::new()
+    }
 }
 
-fn generate_testbench(pipe: &Pipeline) -> String {
+fn generate_testbench(pipe: &Pipeline, backend: &dyn Backend) -> String {
     let Pipeline {
-        clock,
-        name,
-        description,
         stages,
         hazards,
         forwards,
+        ..
     } = pipe;
 
-    let _clock_ = match clock {
-        Edge::Posedge(name) => format!("posedge {name}"),
-        Edge::Negedge(name) => format!("negedge {name}"),
-        Edge::Edge(name) => format!("edge {name}"),
-    };
-
     let _stages_ = helper! {
         for stage in stages,
         >>> 4,
         +++ "\n",
-        generate_stage_report(stage),
+        backend.stage_report(stage),
     };
 
     let _hazards_ = helper! {
         for hazard in hazards,
         >>> 4,
         +++ "\n",
-        generate_hazard_report(hazard),
+        backend.hazard_report(hazard),
     };
 
     let _forwards_ = helper! {
         for forward in forwards,
         >>> 4,
         +++ "\n",
-        generate_forward_report(forward),
+        backend.forward_report(forward),
     };
 
+    let _preamble_ = backend.preamble(pipe);
+    let _stages_header_ = backend.section_header("STAGES");
+    let _hazards_header_ = backend.section_header("HAZARDS");
+    let _forwards_header_ = backend.section_header("FORWARDS");
+    let _separator_ = backend.separator();
+    let _footer_ = backend.footer(pipe);
+
     format!(
-        r#"function string status(input stall, flush);
+        r#"{_preamble_}
+    {_stages_header_}
+    {_stages_}
+    {_separator_}
+    {_hazards_header_}
+    {_hazards_}
+    {_separator_}
+    {_forwards_header_}
+    {_forwards_}
+{_footer_}"#
+    )
+}
 
-    case (1'b1)
-        flush:   return "\x1B[1;31m【FLUSH】 \x1B[0m";
-        stall:   return "\x1B[1;33m【STALL】 \x1B[0m";
-        default: return "\x1B[1;32m【ACTIVE】\x1B[0m";
-    endcase
+#[derive(Debug, Clone, Copy)]
+enum GraphKind {
+    Digraph,
+    Graph,
+}
 
-endfunction
-        
-function string hazard_mark(input condition);
+impl GraphKind {
+    fn keyword(&self) -> &'static str {
+        match self {
+            GraphKind::Digraph => "digraph",
+            GraphKind::Graph => "graph",
+        }
+    }
 
-    if (condition) return "\x1B[1;33m ⚠ ";
-    else           return "\x1B[1;32m ∅ ";
+    fn edgeop(&self) -> &'static str {
+        match self {
+            GraphKind::Digraph => "->",
+            GraphKind::Graph => "--",
+        }
+    }
+}
 
-endfunction
+fn generate_dot(pipe: &Pipeline, kind: GraphKind) -> String {
+    let Pipeline {
+        name,
+        stages,
+        hazards,
+        forwards,
+        ..
+    } = pipe;
 
-function string forward_mark(input condition);
+    let edgeop = kind.edgeop();
 
-    if (condition) return "\x1B[1;33m → ";
-    else           return "\x1B[1;37m ∅ ";
+    let _nodes_ = helper! {
+        for stage in stages,
+        >>> 4,
+        +++ "\n",
+        format!(
+            r#""{}" [label="{}", shape=box, tooltip="{}"];"#,
+            stage.name, stage.name, stage.description
+        ),
+    };
 
-endfunction
+    let _path_ = helper! {
+        for window in stages.windows(2),
+        >>> 4,
+        +++ "\n",
+        format!(r#""{}" {edgeop} "{}";"#, window[0].name, window[1].name),
+    };
 
-initial begin
+    let _forwards_ = helper! {
+        for forward in forwards,
+        >>> 4,
+        +++ "\n",
+        format!(
+            r#""{}" {edgeop} "{}" [label="{}", style=dashed, tooltip="{}"];"#,
+            forward.from.name, forward.to.name, forward.name, forward.description
+        ),
+    };
 
-    $display("███   {name}: {description}   ███");
-    $display();
-    $display();
+    let _hazards_ = helper! {
+        for hazard in hazards,
+        >>> 4,
+        +++ "\n",
+        {
+            let boundary = hazard.name.split('/').next().unwrap_or(&hazard.name);
+            let neighbour = stages.iter().find(|stage| stage.name == boundary);
+
+            let node = format!(
+                r#""hazard: {}" [label="⚠ {}", shape=note, style=filled, fillcolor=lightyellow, tooltip="{}"];"#,
+                hazard.name, hazard.name, hazard.description
+            );
+
+            match neighbour {
+                Some(stage) => format!(
+                    "{node}\n\"hazard: {}\" {edgeop} \"{}\" [style=dotted, arrowhead=none];",
+                    hazard.name, stage.name
+                ),
+                None => node,
+            }
+        },
+    };
 
-end
+    format!(
+        r#"{} "{name}" {{
+    rankdir=LR;
 
-always @({_clock_}) begin
+    {_nodes_}
 
-	$display("───────────────────────────────────────────────────");
-	$display();
+    {_path_}
 
-    $display("= STAGES =");
-    {_stages_}
-    $display();
-    $display("= HAZARDS =");
-    {_hazards_}
-    $display();
-    $display("= FORWARDS =");
     {_forwards_}
-    $display();
 
-end"#
+    {_hazards_}
+}}"#,
+        kind.keyword()
     )
 }
 
-fn main() {
-    let fetch = Rc::new(Stage {
-        name: "IF".into(),
-        description: "Instruction Fetch".into(),
-        stall: Some("stall_fetch".into()),
-        flush: Some("warp".into()),
-    });
-
-    let decode = Rc::new(Stage {
-        name: "ID".into(),
-        description: "Instruction Decode".into(),
-        stall: Some("stall_decode".into()),
-        flush: Some("warp".into()),
-    });
-
-    let execute = Rc::new(Stage {
-        name: "IF".into(),
-        description: "Execute".into(),
-        stall: Some("stall_execute".into()),
-        flush: Some("warp".into()),
-    });
-
-    let writeback = Rc::new(Stage {
-        name: "WB".into(),
-        description: "Write-back".into(),
-        stall: None,
-        flush: None,
-    });
-
-    let hazards = vec![
-        Hazard {
-            name: "ID/EX".into(),
-            description: "foo".into(),
-            condition: "conflict_decode_1 || conflict_decode_2".into(),
-        },
-        Hazard {
-            name: "EX/EX".into(),
-            description: "foo".into(),
-            condition: "conflict_execute_1 || conflict_execute_2".into(),
-        },
-    ];
-
-    let forwards = vec![
-        Forward {
-            name: "ID/EX (rs1)".into(),
-            description: "foo".into(),
-            condition: "conflict_decode_1".into(),
-            from: decode.clone(),
-            to: execute.clone(),
-        },
-        Forward {
-            name: "ID/EX (rs2)".into(),
-            description: "foo".into(),
-            condition: "conflict_decode_2".into(),
-            from: decode.clone(),
-            to: execute.clone(),
-        },
-        Forward {
-            name: "EX/EX (rs1)".into(),
-            description: "foo".into(),
-            condition: "conflict_execute_1 && !cannot_forward_execute".into(),
+/// Which [`Backend`] to render a pipeline definition with.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum BackendKind {
+    /// A SystemVerilog testbench with colorized `$display` output.
+    #[default]
+    SystemVerilog,
+    /// A plain, uncolored text report.
+    PlainText,
+}
+
+impl BackendKind {
+    fn build(self) -> Box<dyn Backend> {
+        match self {
+            BackendKind::SystemVerilog => Box::new(SystemVerilogBackend),
+            BackendKind::PlainText => Box::new(PlainTextBackend),
+        }
+    }
+}
+
+impl fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendKind::SystemVerilog => write!(f, "system-verilog"),
+            BackendKind::PlainText => write!(f, "plain-text"),
+        }
+    }
+}
+
+#[derive(Parser)]
+#[command(name = "gac-trabajo", about = "CPU pipeline testbench generator")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a testbench from a pipeline definition.
+    Generate {
+        /// Path to the pipeline definition (TOML).
+        file: PathBuf,
+        /// Where to write the testbench. Prints to stdout if omitted.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Which backend to render the testbench with.
+        #[arg(short, long, value_enum, default_value_t = BackendKind::SystemVerilog)]
+        backend: BackendKind,
+        /// Also emit a Graphviz DOT diagram of the pipeline.
+        #[arg(long)]
+        dot: bool,
+        /// Render the DOT diagram as an undirected `graph` instead of a `digraph`.
+        #[arg(long, requires = "dot")]
+        undirected: bool,
+    },
+    /// Parse and validate a pipeline definition without generating anything.
+    Check {
+        /// Path to the pipeline definition (TOML).
+        file: PathBuf,
+    },
+}
+
+fn write_output(path: Option<&Path>, contents: &str) -> io::Result<()> {
+    match path {
+        Some(path) => fs::write(path, contents),
+        None => {
+            println!("{contents}");
+            Ok(())
+        }
+    }
+}
+
+fn generate(
+    file: &Path,
+    output: Option<&Path>,
+    backend: BackendKind,
+    emit_dot: bool,
+    undirected: bool,
+) -> ExitCode {
+    let pipe = match loader::load_pipeline(file) {
+        Ok(pipe) => pipe,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let warnings = match validate::validate(&pipe) {
+        Ok(warnings) => warnings,
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("{error}");
+            }
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for warning in &warnings {
+        eprintln!("{warning}");
+    }
+
+    let src = generate_testbench(&pipe, backend.build().as_ref());
+
+    if let Err(err) = write_output(output, &src) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    if emit_dot {
+        let dot_path = output.map(|path| path.with_extension("dot"));
+        let kind = if undirected {
+            GraphKind::Graph
+        } else {
+            GraphKind::Digraph
+        };
+
+        if let Err(err) = write_output(dot_path.as_deref(), &generate_dot(&pipe, kind)) {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn check(file: &Path) -> ExitCode {
+    let pipe = match loader::load_pipeline(file) {
+        Ok(pipe) => pipe,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match validate::validate(&pipe) {
+        Ok(warnings) => {
+            for warning in &warnings {
+                eprintln!("{warning}");
+            }
+
+            println!("{} is valid", file.display());
+            ExitCode::SUCCESS
+        }
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("{error}");
+            }
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Generate {
+            file,
+            output,
+            backend,
+            dot,
+            undirected,
+        } => generate(&file, output.as_deref(), backend, dot, undirected),
+        Command::Check { file } => check(&file),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(name: &str, description: &str) -> Rc<Stage> {
+        Rc::new(Stage {
+            name: name.to_string(),
+            description: description.to_string(),
+            stall: None,
+            flush: None,
+        })
+    }
+
+    fn pipeline(stages: Vec<Rc<Stage>>, hazards: Vec<Hazard>, forwards: Vec<Forward>) -> Pipeline {
+        Pipeline {
+            name: "demo".to_string(),
+            description: "a tiny pipeline".to_string(),
+            clock: Edge::Posedge("clock".to_string()),
+            stages,
+            hazards,
+            forwards,
+        }
+    }
+
+    #[test]
+    fn generate_dot_emits_a_node_per_stage() {
+        let pipe = pipeline(
+            vec![stage("IF", "fetch"), stage("ID", "decode")],
+            vec![],
+            vec![],
+        );
+
+        let dot = generate_dot(&pipe, GraphKind::Digraph);
+
+        assert!(dot.contains(r#""IF" [label="IF", shape=box, tooltip="fetch"];"#));
+        assert!(dot.contains(r#""ID" [label="ID", shape=box, tooltip="decode"];"#));
+        assert!(dot.contains(r#""IF" -> "ID";"#));
+    }
+
+    #[test]
+    fn generate_dot_uses_graph_keyword_and_edgeop_when_undirected() {
+        let pipe = pipeline(
+            vec![stage("IF", "fetch"), stage("ID", "decode")],
+            vec![],
+            vec![],
+        );
+
+        let dot = generate_dot(&pipe, GraphKind::Graph);
+
+        assert!(dot.starts_with(r#"graph "demo" {"#));
+        assert!(dot.contains(r#""IF" -- "ID";"#));
+    }
+
+    #[test]
+    fn generate_dot_styles_forwards_as_dashed_edges() {
+        let fetch = stage("IF", "fetch");
+        let execute = stage("EX", "execute");
+
+        let forward = Forward {
+            name: "fwd".to_string(),
+            description: "forward ALU result".to_string(),
+            condition: "valid".to_string(),
             from: execute.clone(),
-            to: execute.clone(),
-        },
-        Forward {
-            name: "EX/EX (rs2)".into(),
-            description: "foo".into(),
-            condition: "conflict_execute_2 && !cannot_forward_execute".into(),
+            to: fetch.clone(),
+        };
+
+        let pipe = pipeline(vec![fetch, execute], vec![], vec![forward]);
+
+        let dot = generate_dot(&pipe, GraphKind::Digraph);
+
+        assert!(dot.contains(
+            r#""EX" -> "IF" [label="fwd", style=dashed, tooltip="forward ALU result"];"#
+        ));
+    }
+
+    #[test]
+    fn generate_dot_attaches_hazard_to_matching_stage_boundary() {
+        let pipe = pipeline(
+            vec![stage("ID", "decode")],
+            vec![Hazard {
+                name: "ID/load-use".to_string(),
+                description: "load followed by dependent use".to_string(),
+                condition: "load_use_detected".to_string(),
+            }],
+            vec![],
+        );
+
+        let dot = generate_dot(&pipe, GraphKind::Digraph);
+
+        assert!(dot.contains(r#""hazard: ID/load-use" [label="⚠ ID/load-use""#));
+        assert!(dot.contains(r#""hazard: ID/load-use" -> "ID" [style=dotted, arrowhead=none];"#));
+    }
+
+    #[test]
+    fn generate_dot_omits_boundary_edge_when_no_stage_matches() {
+        let pipe = pipeline(
+            vec![stage("ID", "decode")],
+            vec![Hazard {
+                name: "MEM/structural".to_string(),
+                description: "no matching stage".to_string(),
+                condition: "busy".to_string(),
+            }],
+            vec![],
+        );
+
+        let dot = generate_dot(&pipe, GraphKind::Digraph);
+
+        assert!(dot.contains(r#""hazard: MEM/structural" [label="⚠ MEM/structural""#));
+        assert!(!dot.contains("-> \"MEM\""));
+    }
+
+    fn sample_pipeline() -> Pipeline {
+        let fetch = stage("IF", "fetch");
+        let execute = stage("EX", "execute");
+
+        let hazard = Hazard {
+            name: "load-use".to_string(),
+            description: "load followed by dependent use".to_string(),
+            condition: "load_use_detected".to_string(),
+        };
+
+        let forward = Forward {
+            name: "ex_to_if".to_string(),
+            description: "forward ALU result".to_string(),
+            condition: "fwd_valid".to_string(),
             from: execute.clone(),
-            to: execute.clone(),
-        },
-    ];
+            to: fetch.clone(),
+        };
 
-    let pipe = Pipeline {
-        name: "RISCV".into(),
-        description: "Custom RISC-V (RV32I) CPU".into(),
-        clock: Edge::Posedge("clock".into()),
-        stages: vec![fetch, decode, execute, writeback],
-        hazards,
-        forwards,
-    };
+        pipeline(vec![fetch, execute], vec![hazard], vec![forward])
+    }
+
+    #[test]
+    fn system_verilog_backend_renders_a_colored_testbench() {
+        let pipe = sample_pipeline();
+        let src = generate_testbench(&pipe, &SystemVerilogBackend);
 
-    let src = generate_testbench(&pipe);
-    println!("{}", src);
+        assert!(src.contains("███   demo: a tiny pipeline   ███"));
+        assert!(src.contains(r#"$display("= STAGES =");"#));
+        assert!(src.contains(r#"$display("%s IF\x1B[0m", status(1'b0, 1'b0));"#));
+        assert!(src.contains(r#"$display("%s load-use\x1B[0m", hazard_mark(load_use_detected));"#));
+        assert!(src.contains(r#"$display("%s ex_to_if\x1B[0m", forward_mark(fwd_valid));"#));
+    }
+
+    #[test]
+    fn plain_text_backend_renders_uncolored_lines() {
+        let pipe = sample_pipeline();
+        let src = generate_testbench(&pipe, &PlainTextBackend);
+
+        assert!(src.contains("demo: a tiny pipeline"));
+        assert!(src.contains("-- STAGES --"));
+        assert!(src.contains("IF: stall=idle flush=idle"));
+        assert!(src.contains("load-use: load_use_detected"));
+        assert!(src.contains("ex_to_if: fwd_valid"));
+        assert!(!src.contains("\x1B"));
+    }
+
+    #[test]
+    fn backend_kind_builds_the_matching_backend() {
+        let pipe = sample_pipeline();
+
+        let sv = generate_testbench(&pipe, BackendKind::SystemVerilog.build().as_ref());
+        assert!(sv.contains("initial begin"));
+
+        let plain = generate_testbench(&pipe, BackendKind::PlainText.build().as_ref());
+        assert!(plain.contains("-- STAGES --"));
+        assert!(!plain.contains("initial begin"));
+    }
 }
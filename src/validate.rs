@@ -0,0 +1,403 @@
+use std::{collections::HashSet, fmt, rc::Rc};
+
+use crate::Pipeline;
+
+/// Whether a [`ValidationError`] should block code generation or merely be surfaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A structural problem found in a [`Pipeline`] definition, tagged with how serious it is.
+#[derive(Debug)]
+pub struct ValidationError {
+    pub severity: Severity,
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    fn error(kind: ValidationErrorKind) -> Self {
+        ValidationError {
+            severity: Severity::Error,
+            kind,
+        }
+    }
+
+    fn warning(kind: ValidationErrorKind) -> Self {
+        ValidationError {
+            severity: Severity::Warning,
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "error: {}", self.kind),
+            Severity::Warning => write!(f, "warning: {}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+#[derive(Debug)]
+pub enum ValidationErrorKind {
+    DuplicateStage(String),
+    /// `load_pipeline` already refuses to build a [`Pipeline`] with a dangling forward
+    /// reference, so this only fires for pipelines assembled by hand rather than loaded
+    /// from a definition file. Kept as defense-in-depth for that path.
+    UnknownForwardStage {
+        forward: String,
+        stage: String,
+    },
+    BackwardsForward {
+        forward: String,
+        from: String,
+        to: String,
+    },
+    MalformedCondition {
+        name: String,
+        condition: String,
+    },
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationErrorKind::DuplicateStage(name) => {
+                write!(f, "duplicate stage name {name:?}")
+            }
+            ValidationErrorKind::UnknownForwardStage { forward, stage } => write!(
+                f,
+                "forward {forward:?} refers to stage {stage:?}, which is not part of the pipeline"
+            ),
+            ValidationErrorKind::BackwardsForward { forward, from, to } => write!(
+                f,
+                "forward {forward:?} goes from {from:?} to {to:?}, but forwarding normally flows from a later stage back to an earlier one"
+            ),
+            ValidationErrorKind::MalformedCondition { name, condition } => write!(
+                f,
+                "condition for {name:?} looks empty or malformed: {condition:?}"
+            ),
+        }
+    }
+}
+
+fn is_malformed_condition(condition: &str) -> bool {
+    if condition.trim().is_empty() {
+        return true;
+    }
+
+    let mut depth = 0i32;
+
+    for ch in condition.chars() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+
+                if depth < 0 {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    depth != 0
+}
+
+/// Runs structural checks over a [`Pipeline`] before code generation, catching mistakes
+/// the generator would otherwise emit silently (e.g. two stages sharing a name).
+///
+/// Returns `Ok` with any non-fatal warnings when the pipeline is otherwise sound, or `Err`
+/// with every diagnostic (warnings included) as soon as at least one entry is fatal.
+pub fn validate(pipe: &Pipeline) -> Result<Vec<ValidationError>, Vec<ValidationError>> {
+    let mut diagnostics = Vec::new();
+    let mut seen_names = HashSet::new();
+
+    for stage in &pipe.stages {
+        if !seen_names.insert(stage.name.as_str()) {
+            diagnostics.push(ValidationError::error(ValidationErrorKind::DuplicateStage(
+                stage.name.clone(),
+            )));
+        }
+
+        for condition in [&stage.stall, &stage.flush].into_iter().flatten() {
+            if is_malformed_condition(condition) {
+                diagnostics.push(ValidationError::error(
+                    ValidationErrorKind::MalformedCondition {
+                        name: stage.name.clone(),
+                        condition: condition.clone(),
+                    },
+                ));
+            }
+        }
+    }
+
+    for hazard in &pipe.hazards {
+        if is_malformed_condition(&hazard.condition) {
+            diagnostics.push(ValidationError::error(
+                ValidationErrorKind::MalformedCondition {
+                    name: hazard.name.clone(),
+                    condition: hazard.condition.clone(),
+                },
+            ));
+        }
+    }
+
+    for forward in &pipe.forwards {
+        if is_malformed_condition(&forward.condition) {
+            diagnostics.push(ValidationError::error(
+                ValidationErrorKind::MalformedCondition {
+                    name: forward.name.clone(),
+                    condition: forward.condition.clone(),
+                },
+            ));
+        }
+
+        let find = |stage: &Rc<_>| {
+            pipe.stages
+                .iter()
+                .position(|candidate| Rc::ptr_eq(candidate, stage) || candidate.name == stage.name)
+        };
+
+        let from_idx = find(&forward.from);
+        let to_idx = find(&forward.to);
+
+        if from_idx.is_none() {
+            diagnostics.push(ValidationError::error(
+                ValidationErrorKind::UnknownForwardStage {
+                    forward: forward.name.clone(),
+                    stage: forward.from.name.clone(),
+                },
+            ));
+        }
+
+        if to_idx.is_none() {
+            diagnostics.push(ValidationError::error(
+                ValidationErrorKind::UnknownForwardStage {
+                    forward: forward.name.clone(),
+                    stage: forward.to.name.clone(),
+                },
+            ));
+        }
+
+        if let (Some(from_idx), Some(to_idx)) = (from_idx, to_idx) {
+            if from_idx < to_idx {
+                diagnostics.push(ValidationError::warning(
+                    ValidationErrorKind::BackwardsForward {
+                        forward: forward.name.clone(),
+                        from: forward.from.name.clone(),
+                        to: forward.to.name.clone(),
+                    },
+                ));
+            }
+        }
+    }
+
+    if diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity == Severity::Error)
+    {
+        Err(diagnostics)
+    } else {
+        Ok(diagnostics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{Edge, Forward, Hazard, Stage};
+
+    fn stage(name: &str) -> Rc<Stage> {
+        Rc::new(Stage {
+            name: name.to_string(),
+            description: String::new(),
+            stall: None,
+            flush: None,
+        })
+    }
+
+    fn pipeline(stages: Vec<Rc<Stage>>, hazards: Vec<Hazard>, forwards: Vec<Forward>) -> Pipeline {
+        Pipeline {
+            name: "test".to_string(),
+            description: String::new(),
+            clock: Edge::Posedge("clock".to_string()),
+            stages,
+            hazards,
+            forwards,
+        }
+    }
+
+    fn has_kind(
+        diagnostics: &[ValidationError],
+        expected: impl Fn(&ValidationErrorKind) -> bool,
+    ) -> bool {
+        diagnostics
+            .iter()
+            .any(|diagnostic| expected(&diagnostic.kind))
+    }
+
+    #[test]
+    fn accepts_sound_pipeline() {
+        let fetch = stage("IF");
+        let execute = stage("EX");
+        let pipe = pipeline(vec![fetch.clone(), execute.clone()], vec![], vec![]);
+
+        assert!(validate(&pipe).is_ok());
+    }
+
+    #[test]
+    fn rejects_duplicate_stage_names() {
+        let pipe = pipeline(vec![stage("IF"), stage("IF")], vec![], vec![]);
+
+        let diagnostics = validate(&pipe).unwrap_err();
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::DuplicateStage(name) if name == "IF"
+        )));
+    }
+
+    #[test]
+    fn rejects_unknown_forward_stage() {
+        // Hand-assembled `Pipeline` whose forward points at a stage that was never
+        // added to `stages` — `load_pipeline` would reject this before construction,
+        // but `validate` must still catch it for pipelines built programmatically.
+        let fetch = stage("IF");
+        let stray = stage("MEM");
+
+        let forward = Forward {
+            name: "fwd".to_string(),
+            description: String::new(),
+            condition: "valid".to_string(),
+            from: stray,
+            to: fetch.clone(),
+        };
+
+        let pipe = pipeline(vec![fetch], vec![], vec![forward]);
+
+        let diagnostics = validate(&pipe).unwrap_err();
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::UnknownForwardStage { stage, .. } if stage == "MEM"
+        )));
+    }
+
+    #[test]
+    fn rejects_forward_with_both_stages_unknown() {
+        // Neither `from` nor `to` is part of `stages` — both must be reported, not
+        // just the first one `find` fails to resolve.
+        let fetch = stage("IF");
+        let stray_from = stage("MEM");
+        let stray_to = stage("WB");
+
+        let forward = Forward {
+            name: "fwd".to_string(),
+            description: String::new(),
+            condition: "valid".to_string(),
+            from: stray_from,
+            to: stray_to,
+        };
+
+        let pipe = pipeline(vec![fetch], vec![], vec![forward]);
+
+        let diagnostics = validate(&pipe).unwrap_err();
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::UnknownForwardStage { stage, .. } if stage == "MEM"
+        )));
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::UnknownForwardStage { stage, .. } if stage == "WB"
+        )));
+    }
+
+    #[test]
+    fn warns_on_backwards_forward() {
+        let fetch = stage("IF");
+        let execute = stage("EX");
+
+        let forward = Forward {
+            name: "fwd".to_string(),
+            description: String::new(),
+            condition: "valid".to_string(),
+            from: fetch.clone(),
+            to: execute.clone(),
+        };
+
+        let pipe = pipeline(vec![fetch, execute], vec![], vec![forward]);
+
+        let diagnostics = validate(&pipe).unwrap();
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::BackwardsForward { .. }
+        )));
+    }
+
+    #[test]
+    fn accepts_forward_flowing_from_later_to_earlier_stage() {
+        let fetch = stage("IF");
+        let execute = stage("EX");
+
+        let forward = Forward {
+            name: "fwd".to_string(),
+            description: String::new(),
+            condition: "valid".to_string(),
+            from: execute.clone(),
+            to: fetch.clone(),
+        };
+
+        let pipe = pipeline(vec![fetch, execute], vec![], vec![forward]);
+
+        let diagnostics = validate(&pipe).unwrap();
+        assert!(!has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::BackwardsForward { .. }
+        )));
+    }
+
+    #[test]
+    fn rejects_empty_condition() {
+        let pipe = pipeline(
+            vec![],
+            vec![Hazard {
+                name: "load-use".to_string(),
+                description: String::new(),
+                condition: "   ".to_string(),
+            }],
+            vec![],
+        );
+
+        let diagnostics = validate(&pipe).unwrap_err();
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::MalformedCondition { name, .. } if name == "load-use"
+        )));
+    }
+
+    #[test]
+    fn rejects_unbalanced_parens_condition() {
+        let pipe = pipeline(
+            vec![],
+            vec![Hazard {
+                name: "load-use".to_string(),
+                description: String::new(),
+                condition: "(valid && stall".to_string(),
+            }],
+            vec![],
+        );
+
+        let diagnostics = validate(&pipe).unwrap_err();
+        assert!(has_kind(&diagnostics, |kind| matches!(
+            kind,
+            ValidationErrorKind::MalformedCondition { .. }
+        )));
+    }
+}